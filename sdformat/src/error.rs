@@ -0,0 +1,183 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_xml_rs::from_str;
+use xml::common::Position;
+
+use crate::Sdf;
+
+/// Oldest SDFormat version this crate treats as current; older versions are
+/// still parsed, but surface an [`ErrorCode::VersionDeprecated`] from
+/// [`Sdf::from_path`].
+const MIN_SUPPORTED_VERSION: (u32, u32) = (1, 6);
+
+/// Identifies the kind of problem an [`Error`] describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// The `<sdf version="...">` attribute names a deprecated specification version.
+    VersionDeprecated,
+    /// `merge="true"` was set on an include that cannot be merged (e.g. a light or actor).
+    MergeIncludeUnsupported,
+    /// An include's `uri`, or a `model.config` it depends on, could not be found.
+    FileNotFound,
+    /// The XML content could not be parsed as SDF.
+    ParseFailure,
+    /// Merging an include would introduce a name that collides with an existing sibling.
+    NameCollision,
+    /// A model transitively includes itself through its own `<include>` elements.
+    IncludeCycle,
+}
+
+/// A structured SDF error, carrying the originating file (and, where known,
+/// line number) as it propagates across possibly several included files, so
+/// a user sees e.g. `"/foo/model.sdf:42: unsupported merge include"` instead
+/// of an opaque serde message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub code: ErrorCode,
+    pub message: String,
+    pub file: Option<PathBuf>,
+    pub line: Option<usize>,
+}
+
+impl Error {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Error {
+            code,
+            message: message.into(),
+            file: None,
+            line: None,
+        }
+    }
+
+    /// Attaches (or overwrites) the file this error originated from.
+    pub fn with_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+
+    /// Attaches the line number this error originated from, if known.
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Sets `file` to `path` unless a file was already attached, so as an
+    /// error propagates back up through nested `resolve_includes` calls the
+    /// innermost (deepest include) file wins.
+    pub fn propagate_file(mut self, path: impl AsRef<Path>) -> Self {
+        if self.file.is_none() {
+            self.file = Some(path.as_ref().to_path_buf());
+        }
+        self
+    }
+}
+
+/// Builds a [`ParseFailure`](ErrorCode::ParseFailure) error from a
+/// `serde_xml_rs` parse failure, attaching the line number the underlying
+/// XML reader was positioned at when a syntax error occurred (rows are
+/// 0-indexed in `xml-rs`, hence the `+ 1`).
+pub(crate) fn parse_failure(error: serde_xml_rs::Error) -> Error {
+    let line = match &error {
+        serde_xml_rs::Error::Syntax { source } => Some(source.position().row as usize + 1),
+        _ => None,
+    };
+    let error = Error::new(ErrorCode::ParseFailure, error.to_string());
+    match line {
+        Some(line) => error.with_line(line),
+        None => error,
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}", file.display())?;
+            if let Some(line) = self.line {
+                write!(f, ":{line}")?;
+            }
+            write!(f, ": {}", self.message)
+        } else {
+            write!(f, "{}", self.message)
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Sdf {
+    /// Parses the SDF file at `path`, attaching `path` to any resulting
+    /// [`Error`] so failures read as `"<path>: <message>"` rather than an
+    /// opaque serde message.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Sdf, Error> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| Error::new(ErrorCode::FileNotFound, e.to_string()).with_file(path))?;
+        let sdf: Sdf = from_str(&text).map_err(|e| parse_failure(e).with_file(path))?;
+        check_version(&sdf.version).map_err(|e| e.with_file(path))?;
+        Ok(sdf)
+    }
+}
+
+fn check_version(version: &str) -> Result<(), Error> {
+    match parse_major_minor(version) {
+        Some(v) if v < MIN_SUPPORTED_VERSION => Err(Error::new(
+            ErrorCode::VersionDeprecated,
+            format!(
+                "SDFormat version {version} is deprecated; upgrade to {}.{} or newer",
+                MIN_SUPPORTED_VERSION.0, MIN_SUPPORTED_VERSION.1
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn parse_major_minor(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn formats_file_and_line() {
+        let error = Error::new(ErrorCode::MergeIncludeUnsupported, "cannot merge light")
+            .with_file("/foo/model.sdf")
+            .with_line(42);
+        assert_eq!(
+            error.to_string(),
+            "/foo/model.sdf:42: cannot merge light"
+        );
+    }
+
+    #[test]
+    fn formats_without_file() {
+        let error = Error::new(ErrorCode::ParseFailure, "unexpected eof");
+        assert_eq!(error.to_string(), "unexpected eof");
+    }
+
+    #[test]
+    fn extracts_line_from_xml_syntax_error() {
+        let xml = "<sdf version=\"1.8\">\n  <model name=\"box\"\n</sdf>";
+        let result: Result<Sdf, _> = from_str(xml);
+        let error = parse_failure(result.expect_err("malformed xml should fail to parse"));
+        assert_eq!(error.code, ErrorCode::ParseFailure);
+        assert!(error.line.is_some());
+    }
+
+    #[test]
+    fn flags_deprecated_version() {
+        let error = check_version("1.4").expect_err("1.4 should be deprecated");
+        assert_eq!(error.code, ErrorCode::VersionDeprecated);
+    }
+
+    #[test]
+    fn accepts_current_version() {
+        assert!(check_version("1.8").is_ok());
+    }
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use crate::vec3;
+
+/// The shape of a collision or visual element.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Geometry {
+    Box {
+        /// Size of the box in the x, y, and z directions.
+        #[serde(with = "vec3")]
+        size: [f64; 3],
+    },
+    Cylinder {
+        /// Radius of the cylinder.
+        radius: f64,
+        /// Length of the cylinder.
+        length: f64,
+    },
+    Capsule {
+        /// Radius of the capsule.
+        radius: f64,
+        /// Length of the cylindrical portion of the capsule, excluding the two end caps.
+        length: f64,
+    },
+    Sphere {
+        /// Radius of the sphere.
+        radius: f64,
+    },
+    Mesh {
+        /// URI to the mesh file.
+        uri: String,
+        /// Scale to apply to the mesh in the x, y, and z directions.
+        /// Default: 1 1 1
+        #[serde(default = "vec3::ones", with = "vec3")]
+        scale: [f64; 3],
+    },
+    Plane,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_xml_rs::from_str;
+
+    #[test]
+    fn parses_box_geometry() {
+        let geometry: Geometry = from_str("<box><size>1 2 3</size></box>")
+            .expect("should deserialize");
+        assert_eq!(geometry, Geometry::Box { size: [1.0, 2.0, 3.0] });
+    }
+
+    #[test]
+    fn parses_sphere_geometry() {
+        let geometry: Geometry =
+            from_str("<sphere><radius>0.5</radius></sphere>").expect("should deserialize");
+        assert_eq!(geometry, Geometry::Sphere { radius: 0.5 });
+    }
+
+    #[test]
+    fn mesh_scale_defaults_to_one() {
+        let geometry: Geometry =
+            from_str(r#"<mesh><uri>model://box/meshes/box.dae</uri></mesh>"#)
+                .expect("should deserialize");
+        assert_eq!(
+            geometry,
+            Geometry::Mesh {
+                uri: "model://box/meshes/box.dae".into(),
+                scale: [1.0, 1.0, 1.0],
+            }
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+use crate::vec3;
+use crate::Pose;
+
+/// A joint connects two links with kinematic and dynamic properties. By
+/// default, the pose of a joint is expressed in the child link frame.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename = "joint", rename_all = "snake_case")]
+pub struct Joint {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub r#type: JointType,
+    /// Name of the parent link.
+    pub parent: String,
+    /// Name of the child link.
+    pub child: String,
+    #[serde(default)]
+    pub pose: Option<Pose>,
+    #[serde(default)]
+    pub axis: Option<Axis>,
+}
+
+/// The kinematic type of a [`Joint`].
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum JointType {
+    Revolute,
+    Prismatic,
+    Continuous,
+    Fixed,
+    Ball,
+    Screw,
+    Universal,
+    Gearbox,
+}
+
+/// The axis of rotation or translation for a [`Joint`], along with its
+/// limits and dynamic properties.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename = "axis", rename_all = "snake_case")]
+pub struct Axis {
+    /// The x, y, z components of the axis unit vector, expressed in the
+    /// joint frame. A `use_parent_model_frame` element is not read.
+    #[serde(with = "vec3")]
+    pub xyz: [f64; 3],
+    #[serde(default)]
+    pub limit: Option<Limit>,
+    #[serde(default)]
+    pub dynamics: Option<Dynamics>,
+}
+
+/// The range of motion permitted for a joint axis.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename = "limit", rename_all = "snake_case")]
+pub struct Limit {
+    /// Lower joint limit (radians for revolute joints, meters for prismatic joints).
+    pub lower: f64,
+    /// Upper joint limit.
+    pub upper: f64,
+    /// Maximum force (N) or torque (N-m) that can be applied to the axis.
+    #[serde(default)]
+    pub effort: Option<f64>,
+    /// Maximum velocity of the axis.
+    #[serde(default)]
+    pub velocity: Option<f64>,
+}
+
+/// Physical damping and spring properties applied along a joint axis.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename = "dynamics", rename_all = "snake_case")]
+pub struct Dynamics {
+    /// Viscous damping coefficient, in N-m-s/rad (revolute) or N-s/m (prismatic).
+    #[serde(default)]
+    pub damping: Option<f64>,
+    /// Physical static friction, in N-m (revolute) or N (prismatic).
+    #[serde(default)]
+    pub friction: Option<f64>,
+    /// Spring stiffness, in N-m/rad (revolute) or N/m (prismatic).
+    #[serde(default)]
+    pub spring_stiffness: Option<f64>,
+    /// Spring reference position, where the spring force is zero.
+    #[serde(default)]
+    pub spring_reference: Option<f64>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_xml_rs::from_str;
+
+    #[test]
+    fn parses_revolute_joint() {
+        let xml = r#"
+            <joint type="revolute" name="my_joint">
+                <parent>base_link</parent>
+                <child>arm_link</child>
+                <axis>
+                    <xyz>0 0 1</xyz>
+                    <limit>
+                        <lower>-1.57</lower>
+                        <upper>1.57</upper>
+                    </limit>
+                </axis>
+            </joint>"#;
+
+        let joint: Joint = from_str(xml).expect("should deserialize");
+
+        assert_eq!(joint.name, "my_joint");
+        assert_eq!(joint.r#type, JointType::Revolute);
+        assert_eq!(joint.parent, "base_link");
+        assert_eq!(joint.child, "arm_link");
+
+        let axis = joint.axis.expect("axis");
+        assert_eq!(axis.xyz, [0.0, 0.0, 1.0]);
+        let limit = axis.limit.expect("limit");
+        assert_eq!(limit.lower, -1.57);
+        assert_eq!(limit.upper, 1.57);
+    }
+}
@@ -1,12 +1,29 @@
 use serde::{Deserialize, Serialize};
 
+mod error;
+mod geometry;
+mod joint;
+mod link;
+mod merge;
+mod pose;
+mod resolve;
+#[cfg(feature = "urdf")]
+mod urdf_bridge;
+mod vec3;
+
+pub use error::{Error, ErrorCode};
+pub use geometry::Geometry;
+pub use joint::{Axis, Dynamics, Joint, JointType, Limit};
+pub use link::{Collision, Inertia, Inertial, Link, Visual};
+pub use pose::Pose;
+
 /// SDFormat base element that can include one model, actor, light, or worlds.
 /// A user of multiple worlds could run parallel instances of simulation,
 /// or offer selection of a world at runtime.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename = "sdf", rename_all = "snake_case")]
 pub struct Sdf {
-    #[serde(default = "Sdf::default_version", rename="$value")]
+    #[serde(default = "Sdf::default_version")]
     /// Version number of the SDFormat specification.
     pub version: String,
     #[serde(rename = "$value")]
@@ -25,11 +42,11 @@ impl Sdf {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum SdfType {
     World(World),
-    Model(Model),
+    Model(Box<Model>),
     Actor,
     Light,
 }
@@ -39,10 +56,6 @@ pub enum SdfType {
 #[serde(rename = "world", rename_all = "snake_case")]
 pub struct World {}
 
-fn true_default() -> bool {
-    true
-}
-
 fn some_true_default() -> Option<bool> {
     Some(true)
 }
@@ -51,13 +64,8 @@ fn some_false_default() -> Option<bool> {
     Some(false)
 }
 
-/// A position(x,y,z) and orientation(roll, pitch yaw) with respect to the frame named in the relative_to attribute.
-/// Default: 0 0 0 0 -0 0
-/// TODO: Create `Pose`
-pub type Pose = String;
-
 /// The model element defines a complete robot or any other physical object.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename = "model", rename_all = "snake_case")]
 pub struct Model {
     /// A unique name for the model. This name must not match another model in the world.
@@ -79,8 +87,9 @@ pub struct Model {
     pub allow_auto_disable: Option<bool>,
     #[serde(default)]
     pub include: Vec<Include>,
+    /// Nested models, each its own complete `<model>` element.
     #[serde(default)]
-    pub model: Vec<NestedModel>,
+    pub model: Vec<Model>,
     /// If set to true, all links in the model will be affected by the wind. Can be overriden by the link wind property.
     #[serde(default = "some_false_default")]
     pub enable_wind: Option<bool>,
@@ -99,7 +108,7 @@ pub struct Model {
 }
 
 /// Include resources from a URI. This can be used to nest models. Included resources can only contain one 'model', 'light' or 'actor' element. The URI can point to a directory or a file. If the URI is a directory, it must conform to the model database structure (see /tutorials?tut=composition&cat=specification&#defining-models-in-separate-files).
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename = "include", rename_all = "snake_case")]
 pub struct Include {
     /// URI to a resource, such as a model
@@ -111,7 +120,13 @@ pub struct Include {
     /// The frame inside the included model whose pose will be set by the specified pose element. If this element is specified, the pose must be specified.
     pub placement_frame: Option<String>,
     pub pose: Option<Pose>,
+    #[serde(default)]
     pub plugin: Vec<Plugin>,
+    /// If true, the included model's children (links, joints, frames, nested
+    /// models, and plugins) are spliced directly into the parent model
+    /// instead of being nested as a separate model scope.
+    #[serde(default)]
+    pub merge: Option<bool>,
 }
 
 /// A plugin is a dynamically loaded chunk of code. It can exist as a child of world, model, and sensor.
@@ -126,40 +141,16 @@ pub struct Plugin {
 }
 
 /// A frame of reference to which a pose is relative.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename = "frame", rename_all = "snake_case")]
 pub struct Frame {
     /// Name of the frame. This name must not match another frame defined inside the parent that this frame is attached to.
-    name: String,
+    pub name: String,
     /// Name of the link or frame to which this frame is attached. If a frame is specified, recursively following the attached_to attributes of the specified frames must lead to the name of a link, a model, or the world frame.
     pub attached_to: Option<String>,
     pub pose: Option<Pose>,
 }
 
-/// A nested model element
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename = "model", rename_all = "snake_case")]
-pub struct NestedModel {
-    /// A unique name for the model. This name must not match another nested model in the same level as this model.
-    name: String,
-}
-
-/// A physical link with inertia, collision, and visual properties.
-/// A link must be a child of a model, and any number of links may exist in a model.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename = "link", rename_all = "snake_case")]
-pub struct Link {
-    name: String,
-    // TODO: rest of elements
-}
-
-/// /// A joint connects two links with kinematic and dynamic properties. By default, the pose of a joint is expressed in the child link frame.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename = "joint", rename_all = "snake_case")]
-pub struct Joint {
-    // TODO: Rest of attributes and elements
-}
-
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename = "gripper", rename_all = "snake_case")]
 pub struct Gripper {
@@ -199,15 +190,18 @@ mod test {
     use pretty_assertions::assert_eq;
     use serde_xml_rs::{from_reader, from_str, to_string};
 
-    /// **NOTE:** Version 1.5
-    /// https://github.com/PX4/PX4-SITL_gazebo/blob/master/worlds/typhoon_h480.world
-    pub static PX4_TYPHOON_WORLD: &'static str = include_str!("../tests/px4_typhoon_h480.world");
+    /// Hand-written version 1.5 world fixture, modeled loosely on the
+    /// structure of a typical PX4 SITL world (sun/ground_plane includes,
+    /// physics, scene, spherical_coordinates, a merged model include, and a
+    /// plugin) to exercise parsing of a larger, more realistic document.
+    /// This is not a copy of any specific upstream world file.
+    pub static LARGE_WORLD_FIXTURE: &str = include_str!("../tests/large_world_fixture.world");
 
     #[test]
     #[ignore = "Still needs some work to deserialize the file"]
-    fn it_can_deserialize_px4_typhoon_world() {
+    fn it_can_deserialize_a_large_world() {
         // deserialize only
-        let actual_sdf: Sdf = from_str(PX4_TYPHOON_WORLD).expect("Should deserialize");
+        let actual_sdf: Sdf = from_str(LARGE_WORLD_FIXTURE).expect("Should deserialize");
 
         // serialize & deserialize result and check it
         {
@@ -236,17 +230,17 @@ mod test {
         //         <Item name="hello" source="world.rs" />
         //     </Project>
         // "##;
-        let sdf: Sdf = from_reader(sdf_xml.as_bytes()).unwrap();
+        let sdf: Sdf = from_reader(sdf_xml.trim().as_bytes()).unwrap();
 
         let expected = Sdf {
             version: String::from("1.8"),
-            sdf_type: SdfType::Model(Model {
+            sdf_type: SdfType::Model(Box::new(Model {
                 name: "box".into(),
                 canonical_link: None,
                 placement_frame: None,
                 r#static: Some(false),
                 self_collide: Some(true),
-                pose: Some("0 0 0.5 0 0 0".into()),
+                pose: Some(Pose::new(0.0, 0.0, 0.5, 0.0, 0.0, 0.0)),
                 allow_auto_disable: Some(true),
                 include: vec![],
                 model: vec![],
@@ -256,21 +250,13 @@ mod test {
                 joint: vec![],
                 plugin: vec![],
                 gripper: vec![],
-            }),
+            })),
         };
         assert_eq!(expected, sdf);
     }
 
     #[test]
     fn test_model() {
-        // TODO: Add joints and links:
-        // <link name="link">
-        // ...
-        // </link>
-        // <joint type="revolute" name="my_joint">
-        // ...
-        // </joint>
-
         let xml = r#"
             <?xml version="1.0" ?>
             <sdf version="1.5">
@@ -278,32 +264,50 @@ mod test {
                     <pose>0 0 0.5 0 0 0</pose>
                     <static>false</static>
                     <plugin filename="libMyPlugin.so" name="my_plugin"/>
+                    <link name="link">
+                    </link>
+                    <joint type="revolute" name="my_joint">
+                        <parent>link</parent>
+                        <child>link</child>
+                    </joint>
                 </model>
             </sdf>"#;
-        let actual = from_str(xml).expect("Should deserialize model");
+        let actual = from_str(xml.trim()).expect("Should deserialize model");
 
         let expected = Sdf {
             version: String::from("1.5"),
-            sdf_type: SdfType::Model(Model {
+            sdf_type: SdfType::Model(Box::new(Model {
                 name: "box".into(),
                 canonical_link: None,
                 placement_frame: None,
                 r#static: Some(false),
                 self_collide: None,
-                pose: Some("0 0 0.5 0 0 0".into()),
+                pose: Some(Pose::new(0.0, 0.0, 0.5, 0.0, 0.0, 0.0)),
                 allow_auto_disable: Some(true), // Default for this value is `true`
                 include: vec![],
                 model: vec![],
                 enable_wind: Some(false),
                 frame: vec![],
-                link: vec![],
-                joint: vec![],
+                link: vec![Link {
+                    name: "link".into(),
+                    inertial: None,
+                    collision: vec![],
+                    visual: vec![],
+                }],
+                joint: vec![Joint {
+                    name: "my_joint".into(),
+                    r#type: JointType::Revolute,
+                    parent: "link".into(),
+                    child: "link".into(),
+                    pose: None,
+                    axis: None,
+                }],
                 plugin: vec![Plugin {
                     filename: "libMyPlugin.so".into(),
                     name: "my_plugin".into(),
                 }],
                 gripper: vec![],
-            }),
+            })),
         };
 
 
@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::Geometry;
+use crate::Pose;
+
+/// A physical link with inertia, collision, and visual properties.
+/// A link must be a child of a model, and any number of links may exist in a model.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename = "link", rename_all = "snake_case")]
+pub struct Link {
+    pub name: String,
+    #[serde(default)]
+    pub inertial: Option<Inertial>,
+    #[serde(default)]
+    pub collision: Vec<Collision>,
+    #[serde(default)]
+    pub visual: Vec<Visual>,
+}
+
+/// The inertial properties of a link: its mass and moment of inertia.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename = "inertial", rename_all = "snake_case")]
+pub struct Inertial {
+    /// Mass of the link, in kg.
+    /// Default: 1.0
+    #[serde(default = "Inertial::default_mass")]
+    pub mass: f64,
+    /// Pose of the inertial frame, relative to the link frame.
+    #[serde(default)]
+    pub pose: Option<Pose>,
+    #[serde(default)]
+    pub inertia: Inertia,
+}
+
+impl Inertial {
+    fn default_mass() -> f64 {
+        1.0
+    }
+}
+
+/// The 3x3 rotational inertia matrix, expressed in the inertial frame.
+/// Because this matrix is symmetric, only the 6 elements on and above the diagonal are needed.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename = "inertia", rename_all = "snake_case")]
+pub struct Inertia {
+    /// Default: 1.0
+    #[serde(default = "Inertia::default_diagonal")]
+    pub ixx: f64,
+    /// Default: 0.0
+    #[serde(default)]
+    pub ixy: f64,
+    /// Default: 0.0
+    #[serde(default)]
+    pub ixz: f64,
+    /// Default: 1.0
+    #[serde(default = "Inertia::default_diagonal")]
+    pub iyy: f64,
+    /// Default: 0.0
+    #[serde(default)]
+    pub iyz: f64,
+    /// Default: 1.0
+    #[serde(default = "Inertia::default_diagonal")]
+    pub izz: f64,
+}
+
+impl Inertia {
+    fn default_diagonal() -> f64 {
+        1.0
+    }
+}
+
+impl Default for Inertia {
+    fn default() -> Self {
+        Inertia {
+            ixx: Inertia::default_diagonal(),
+            ixy: 0.0,
+            ixz: 0.0,
+            iyy: Inertia::default_diagonal(),
+            iyz: 0.0,
+            izz: Inertia::default_diagonal(),
+        }
+    }
+}
+
+/// The collision properties of a link, used for collision checking.
+/// A link can have zero or more collision elements.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename = "collision", rename_all = "snake_case")]
+pub struct Collision {
+    /// A unique name for the collision element, scoped to its parent link.
+    pub name: String,
+    #[serde(default)]
+    pub pose: Option<Pose>,
+    pub geometry: Geometry,
+}
+
+/// The visual properties of a link, used to render it.
+/// A link can have zero or more visual elements.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename = "visual", rename_all = "snake_case")]
+pub struct Visual {
+    /// A unique name for the visual element, scoped to its parent link.
+    pub name: String,
+    #[serde(default)]
+    pub pose: Option<Pose>,
+    pub geometry: Geometry,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_xml_rs::from_str;
+
+    #[test]
+    fn parses_link_with_inertial_collision_and_visual() {
+        let xml = r#"
+            <link name="link">
+                <inertial>
+                    <mass>1.2</mass>
+                    <inertia>
+                        <ixx>0.1</ixx>
+                        <iyy>0.1</iyy>
+                        <izz>0.1</izz>
+                    </inertia>
+                </inertial>
+                <collision name="collision">
+                    <geometry>
+                        <box><size>1 1 1</size></box>
+                    </geometry>
+                </collision>
+                <visual name="visual">
+                    <geometry>
+                        <sphere><radius>0.5</radius></sphere>
+                    </geometry>
+                </visual>
+            </link>"#;
+
+        let link: Link = from_str(xml).expect("should deserialize");
+
+        assert_eq!(link.name, "link");
+        assert_eq!(link.inertial.as_ref().expect("inertial").mass, 1.2);
+        assert_eq!(link.collision.len(), 1);
+        assert_eq!(
+            link.collision[0].geometry,
+            Geometry::Box { size: [1.0, 1.0, 1.0] }
+        );
+        assert_eq!(link.visual.len(), 1);
+        assert_eq!(
+            link.visual[0].geometry,
+            Geometry::Sphere { radius: 0.5 }
+        );
+    }
+}
@@ -0,0 +1,344 @@
+use std::collections::HashSet;
+
+use crate::error::{Error, ErrorCode};
+use crate::{Include, Model};
+
+/// Splices `included`'s children (links, joints, frames, nested models, and
+/// plugins) directly into `parent`. Joint `parent`/`child` names, frame
+/// `attached_to` values, and pose `relative_to` values that name the
+/// included model's implicit frame (the include's `name`, or else the
+/// included model's name) are renamed to the include's `placement_frame`
+/// when one is given; otherwise they keep naming the implicit frame and are
+/// not redirected to `parent`'s own frame. When the included model or the
+/// include has a `pose`, a frame with that target name is added to `parent`,
+/// holding the included model's own pose offset by the include's pose;
+/// without either pose no such frame is added.
+pub(crate) fn merge_model_into(
+    parent: &mut Model,
+    included: &mut Model,
+    include: &Include,
+) -> Result<(), Error> {
+    let implicit_frame = include
+        .name
+        .clone()
+        .unwrap_or_else(|| included.name.clone());
+    let target_frame = include
+        .placement_frame
+        .clone()
+        .unwrap_or_else(|| implicit_frame.clone());
+
+    for frame in &mut included.frame {
+        if frame.attached_to.as_deref() == Some(implicit_frame.as_str()) {
+            frame.attached_to = Some(target_frame.clone());
+        }
+        rename_pose_relative_to(&mut frame.pose, &implicit_frame, &target_frame);
+    }
+    rename_pose_relative_to(&mut included.pose, &implicit_frame, &target_frame);
+
+    for joint in &mut included.joint {
+        if joint.parent == implicit_frame {
+            joint.parent = target_frame.clone();
+        }
+        if joint.child == implicit_frame {
+            joint.child = target_frame.clone();
+        }
+        rename_pose_relative_to(&mut joint.pose, &implicit_frame, &target_frame);
+    }
+
+    check_no_collisions(parent, included)?;
+
+    let merged_pose = compose_poses(included.pose.clone(), include.pose.clone());
+
+    parent.link.append(&mut included.link);
+    parent.joint.append(&mut included.joint);
+    parent.frame.append(&mut included.frame);
+    parent.model.append(&mut included.model);
+    parent.plugin.append(&mut included.plugin);
+
+    if let Some(pose) = merged_pose {
+        check_no_collision(parent, &target_frame)?;
+        parent.frame.push(crate::Frame {
+            name: target_frame,
+            attached_to: None,
+            pose: Some(pose),
+        });
+    }
+
+    Ok(())
+}
+
+/// Composes the included model's own pose with the include's pose override,
+/// applying the override as an additional offset on top of the included
+/// model's pose rather than replacing it. `relative_to` is taken from the
+/// override when given, falling back to the included model's pose's
+/// `relative_to`.
+fn compose_poses(included: Option<crate::Pose>, r#override: Option<crate::Pose>) -> Option<crate::Pose> {
+    match (included, r#override) {
+        (None, None) => None,
+        (Some(pose), None) | (None, Some(pose)) => Some(pose),
+        (Some(inner), Some(outer)) => Some(crate::Pose {
+            x: inner.x + outer.x,
+            y: inner.y + outer.y,
+            z: inner.z + outer.z,
+            roll: inner.roll + outer.roll,
+            pitch: inner.pitch + outer.pitch,
+            yaw: inner.yaw + outer.yaw,
+            relative_to: outer.relative_to.or(inner.relative_to),
+        }),
+    }
+}
+
+fn rename_pose_relative_to(pose: &mut Option<crate::Pose>, from: &str, to: &str) {
+    if let Some(pose) = pose {
+        if pose.relative_to.as_deref() == Some(from) {
+            pose.relative_to = Some(to.to_string());
+        }
+    }
+}
+
+/// Checks whether `name` collides with an existing child of `parent`.
+pub(crate) fn check_no_collision(parent: &Model, name: &str) -> Result<(), Error> {
+    if existing_names(parent).contains(name) {
+        return Err(name_collision(name));
+    }
+    Ok(())
+}
+
+fn check_no_collisions(parent: &Model, included: &Model) -> Result<(), Error> {
+    let existing = existing_names(parent);
+    for link in &included.link {
+        if existing.contains(link.name.as_str()) {
+            return Err(name_collision(&link.name));
+        }
+    }
+    for joint in &included.joint {
+        if existing.contains(joint.name.as_str()) {
+            return Err(name_collision(&joint.name));
+        }
+    }
+    for frame in &included.frame {
+        if existing.contains(frame.name.as_str()) {
+            return Err(name_collision(&frame.name));
+        }
+    }
+    for nested in &included.model {
+        if existing.contains(nested.name.as_str()) {
+            return Err(name_collision(&nested.name));
+        }
+    }
+    Ok(())
+}
+
+fn name_collision(name: &str) -> Error {
+    Error::new(
+        ErrorCode::NameCollision,
+        format!("merged include introduces duplicate name {name:?}"),
+    )
+}
+
+fn existing_names(model: &Model) -> HashSet<&str> {
+    model
+        .link
+        .iter()
+        .map(|l| l.name.as_str())
+        .chain(model.joint.iter().map(|j| j.name.as_str()))
+        .chain(model.frame.iter().map(|f| f.name.as_str()))
+        .chain(model.model.iter().map(|m| m.name.as_str()))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Frame, Joint, JointType, Link, Pose};
+
+    fn empty_model(name: &str) -> Model {
+        Model {
+            name: name.to_string(),
+            canonical_link: None,
+            placement_frame: None,
+            r#static: None,
+            self_collide: None,
+            allow_auto_disable: None,
+            include: vec![],
+            model: vec![],
+            enable_wind: None,
+            frame: vec![],
+            pose: None,
+            link: vec![],
+            joint: vec![],
+            plugin: vec![],
+            gripper: vec![],
+        }
+    }
+
+    fn link(name: &str) -> Link {
+        Link {
+            name: name.to_string(),
+            inertial: None,
+            collision: vec![],
+            visual: vec![],
+        }
+    }
+
+    fn joint(name: &str, parent: &str, child: &str) -> Joint {
+        Joint {
+            name: name.to_string(),
+            r#type: JointType::Fixed,
+            parent: parent.to_string(),
+            child: child.to_string(),
+            pose: None,
+            axis: None,
+        }
+    }
+
+    fn include(uri: &str) -> Include {
+        Include {
+            uri: uri.to_string(),
+            name: None,
+            r#static: None,
+            placement_frame: None,
+            pose: None,
+            plugin: vec![],
+            merge: Some(true),
+        }
+    }
+
+    #[test]
+    fn splices_links_and_joints_into_parent() {
+        let mut parent = empty_model("robot");
+        let mut included = empty_model("wheel");
+        included.link.push(link("wheel_link"));
+        included
+            .joint
+            .push(joint("wheel_joint", "wheel_link", "wheel_link"));
+
+        merge_model_into(&mut parent, &mut included, &include("model://wheel")).unwrap();
+
+        assert_eq!(parent.link.len(), 1);
+        assert_eq!(parent.link[0].name, "wheel_link");
+        assert_eq!(parent.joint.len(), 1);
+        assert_eq!(parent.joint[0].name, "wheel_joint");
+    }
+
+    #[test]
+    fn renames_references_to_the_implicit_frame() {
+        let mut parent = empty_model("robot");
+        let mut included = empty_model("wheel");
+        included
+            .joint
+            .push(joint("wheel_joint", "wheel", "wheel_link"));
+        included.frame.push(Frame {
+            name: "sensor_frame".into(),
+            attached_to: Some("wheel".into()),
+            pose: None,
+        });
+
+        let mut include = include("model://wheel");
+        include.placement_frame = Some("base_link".into());
+
+        merge_model_into(&mut parent, &mut included, &include).unwrap();
+
+        assert_eq!(parent.joint[0].parent, "base_link");
+        assert_eq!(parent.frame[0].attached_to.as_deref(), Some("base_link"));
+    }
+
+    #[test]
+    fn renames_pose_relative_to_the_implicit_frame() {
+        let mut parent = empty_model("robot");
+        let mut included = empty_model("wheel");
+        included.pose = Some(Pose {
+            relative_to: Some("wheel".into()),
+            ..Pose::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+        });
+
+        let mut include = include("model://wheel");
+        include.placement_frame = Some("base_link".into());
+
+        merge_model_into(&mut parent, &mut included, &include).unwrap();
+
+        assert_eq!(
+            included.pose.as_ref().unwrap().relative_to.as_deref(),
+            Some("base_link")
+        );
+    }
+
+    #[test]
+    fn carries_the_included_models_own_pose_onto_the_merged_frame() {
+        let mut parent = empty_model("robot");
+        let mut included = empty_model("wheel");
+        included.link.push(link("wheel_link"));
+        included.pose = Some(Pose::new(1.0, 2.0, 3.0, 0.0, 0.0, 0.0));
+
+        merge_model_into(&mut parent, &mut included, &include("model://wheel")).unwrap();
+
+        let frame = parent
+            .frame
+            .iter()
+            .find(|f| f.name == "wheel")
+            .expect("a synthetic frame should carry the included pose");
+        let pose = frame.pose.as_ref().expect("frame should have a pose");
+        assert_eq!((pose.x, pose.y, pose.z), (1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn offsets_the_included_models_pose_by_the_include_override() {
+        let mut parent = empty_model("robot");
+        let mut included = empty_model("wheel");
+        included.pose = Some(Pose::new(1.0, 2.0, 3.0, 0.0, 0.0, 0.0));
+
+        let mut include = include("model://wheel");
+        include.pose = Some(Pose::new(10.0, 0.0, 0.0, 0.0, 0.0, 0.0));
+
+        merge_model_into(&mut parent, &mut included, &include).unwrap();
+
+        let frame = &parent.frame[0];
+        let pose = frame.pose.as_ref().expect("frame should have a pose");
+        assert_eq!((pose.x, pose.y, pose.z), (11.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn rejects_a_merged_frame_colliding_with_an_existing_parent_name() {
+        let mut parent = empty_model("robot");
+        parent.link.push(link("wheel"));
+        let mut included = empty_model("wheel");
+        included.pose = Some(Pose::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0));
+
+        let error = merge_model_into(&mut parent, &mut included, &include("model://wheel"))
+            .expect_err("merged frame colliding with an existing link should be rejected");
+        assert_eq!(error.code, ErrorCode::NameCollision);
+    }
+
+    #[test]
+    fn rejects_duplicate_link_names() {
+        let mut parent = empty_model("robot");
+        parent.link.push(link("wheel_link"));
+        let mut included = empty_model("wheel");
+        included.link.push(link("wheel_link"));
+
+        let error = merge_model_into(&mut parent, &mut included, &include("model://wheel"))
+            .expect_err("duplicate link name should be rejected");
+        assert_eq!(error.code, ErrorCode::NameCollision);
+    }
+
+    #[test]
+    fn rejects_duplicate_joint_names() {
+        let mut parent = empty_model("robot");
+        parent.joint.push(joint("shared_joint", "a", "b"));
+        let mut included = empty_model("wheel");
+        included.joint.push(joint("shared_joint", "c", "d"));
+
+        let error = merge_model_into(&mut parent, &mut included, &include("model://wheel"))
+            .expect_err("duplicate joint name should be rejected");
+        assert_eq!(error.code, ErrorCode::NameCollision);
+    }
+
+    #[test]
+    fn check_no_collision_detects_existing_joint_name() {
+        let mut parent = empty_model("robot");
+        parent.joint.push(joint("shared_joint", "a", "b"));
+
+        assert!(check_no_collision(&parent, "shared_joint").is_err());
+        assert!(check_no_collision(&parent, "other_joint").is_ok());
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt;
+
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A position (x, y, z) and orientation (roll, pitch, yaw) with respect to
+/// the frame named in `relative_to`.
+/// Default: 0 0 0 0 0 0
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pose {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub roll: f64,
+    pub pitch: f64,
+    pub yaw: f64,
+    /// Name of the frame this pose is relative to.
+    pub relative_to: Option<String>,
+}
+
+impl Pose {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(x: f64, y: f64, z: f64, roll: f64, pitch: f64, yaw: f64) -> Self {
+        Pose {
+            x,
+            y,
+            z,
+            roll,
+            pitch,
+            yaw,
+            relative_to: None,
+        }
+    }
+
+    fn from_components(components: [f64; 6], relative_to: Option<String>) -> Self {
+        let [x, y, z, roll, pitch, yaw] = components;
+        Pose {
+            x,
+            y,
+            z,
+            roll,
+            pitch,
+            yaw,
+            relative_to,
+        }
+    }
+
+    /// Parses the whitespace-separated `x y z roll pitch yaw` body of a
+    /// `<pose>` element, defaulting to all zeros when the body is empty.
+    fn parse_components(text: &str) -> Result<[f64; 6], String> {
+        let values = text
+            .split_whitespace()
+            .map(|v| {
+                v.parse::<f64>()
+                    .map_err(|e| format!("invalid pose component {:?}: {}", v, e))
+            })
+            .collect::<Result<Vec<f64>, String>>()?;
+
+        if values.is_empty() {
+            return Ok([0.0; 6]);
+        }
+
+        values.try_into().map_err(|values: Vec<f64>| {
+            format!(
+                "pose must have exactly 6 components (x y z roll pitch yaw), got {}",
+                values.len()
+            )
+        })
+    }
+}
+
+impl Default for Pose {
+    fn default() -> Self {
+        Pose::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+    }
+}
+
+impl fmt::Display for Pose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {}",
+            self.x, self.y, self.z, self.roll, self.pitch, self.yaw
+        )
+    }
+}
+
+/// Shadow type used to let serde see both the `relative_to` attribute and
+/// the whitespace-separated float body of a `<pose>` element.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "pose")]
+struct PoseRepr {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    relative_to: Option<String>,
+    #[serde(default, rename = "$value")]
+    value: String,
+}
+
+impl Serialize for Pose {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PoseRepr {
+            relative_to: self.relative_to.clone(),
+            value: self.to_string(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pose {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = PoseRepr::deserialize(deserializer)?;
+        let components = Pose::parse_components(&repr.value).map_err(DeError::custom)?;
+        Ok(Pose::from_components(components, repr.relative_to))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_xml_rs::{from_str, to_string};
+
+    #[test]
+    fn parses_pose_body() {
+        let pose: Pose = from_str("<pose>0 0 0.5 0 0 0</pose>").expect("should deserialize");
+        assert_eq!(pose, Pose::new(0.0, 0.0, 0.5, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn parses_relative_to_attribute() {
+        let pose: Pose =
+            from_str(r#"<pose relative_to="other_frame">1 2 3 0 0 1.57</pose>"#)
+                .expect("should deserialize");
+        assert_eq!(pose.relative_to.as_deref(), Some("other_frame"));
+        assert_eq!(pose.x, 1.0);
+    }
+
+    #[test]
+    fn defaults_to_zero_when_body_is_empty() {
+        let pose: Pose = from_str("<pose/>").expect("should deserialize");
+        assert_eq!(pose, Pose::default());
+    }
+
+    #[test]
+    fn rejects_wrong_component_count() {
+        let result: Result<Pose, _> = from_str("<pose>0 0 0.5</pose>");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn round_trips_to_canonical_string() {
+        let pose = Pose::new(0.0, 0.0, 0.5, 0.0, 0.0, 0.0);
+        let xml = to_string(&pose).expect("should serialize");
+        assert!(xml.contains("0 0 0.5 0 0 0"));
+
+        let deserialized: Pose = from_str(&xml).expect("should deserialize back");
+        assert_eq!(pose, deserialized);
+    }
+}
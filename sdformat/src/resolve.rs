@@ -0,0 +1,343 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_xml_rs::from_str;
+
+use crate::error::{parse_failure, Error, ErrorCode};
+use crate::merge;
+use crate::{Model, Sdf, SdfType};
+
+const MODEL_SCHEME_PREFIX: &str = "model://";
+
+impl Sdf {
+    /// Resolves every `<include>` reachable from this SDF.
+    ///
+    /// Each include's `uri` is resolved against `search_paths` (supporting
+    /// both direct `.sdf` files and `model://`-scheme model-database
+    /// directories containing a `model.config`), recursively resolved for
+    /// its own includes, has its `name`/`static`/`pose`/`placement_frame`
+    /// overrides applied, and is then either merged into its parent
+    /// (`include.merge == Some(true)`) or substituted as a nested model.
+    /// An include whose target is a world, actor, or light is an error when
+    /// merged and is otherwise dropped from the model.
+    pub fn resolve_includes(&mut self, search_paths: &[PathBuf]) -> Result<(), Error> {
+        if let SdfType::Model(model) = &mut self.sdf_type {
+            let mut stack = HashSet::new();
+            resolve_model_includes(model, search_paths, &mut stack, None)?;
+        }
+        Ok(())
+    }
+}
+
+fn resolve_model_includes(
+    model: &mut Model,
+    search_paths: &[PathBuf],
+    stack: &mut HashSet<PathBuf>,
+    current_file: Option<&Path>,
+) -> Result<(), Error> {
+    let includes = std::mem::take(&mut model.include);
+    for include in includes {
+        let (resolved_include, sdf_path) = load_include(&include.uri, search_paths, current_file)?;
+        let merge_requested = include.merge.unwrap_or(false);
+
+        let mut resolved = match resolved_include {
+            ResolvedInclude::Model(resolved) => resolved,
+            ResolvedInclude::NonModel => {
+                if merge_requested {
+                    let error = Error::new(
+                        ErrorCode::MergeIncludeUnsupported,
+                        format!("cannot merge non-model include {:?}", include.uri),
+                    );
+                    return Err(error.with_file(&sdf_path));
+                }
+                continue;
+            }
+        };
+
+        let canonical_path = sdf_path.canonicalize().unwrap_or_else(|_| sdf_path.clone());
+        if !stack.insert(canonical_path.clone()) {
+            let error = Error::new(
+                ErrorCode::IncludeCycle,
+                format!("include cycle detected: {:?} includes itself", sdf_path),
+            );
+            return Err(error.with_file(&sdf_path));
+        }
+
+        resolve_model_includes(&mut resolved, search_paths, stack, Some(&sdf_path))?;
+        stack.remove(&canonical_path);
+
+        if let Some(name) = include.name.clone() {
+            resolved.name = name;
+        }
+        if let Some(is_static) = include.r#static {
+            resolved.r#static = Some(is_static);
+        }
+        if let Some(placement_frame) = include.placement_frame.clone() {
+            resolved.placement_frame = Some(placement_frame);
+        }
+        if include.pose.is_some() && !merge_requested {
+            resolved.pose = include.pose.clone();
+        }
+
+        if merge_requested {
+            merge::merge_model_into(model, &mut resolved, &include)
+                .map_err(|e| tag_file(e, Some(&sdf_path)))?;
+        } else {
+            merge::check_no_collision(model, &resolved.name)
+                .map_err(|e| tag_file(e, Some(&sdf_path)))?;
+            model.model.push(*resolved);
+        }
+    }
+
+    Ok(())
+}
+
+/// Attaches `file` to `error` only if it doesn't already carry one, so the
+/// innermost (deepest include) file that caused the error wins as it
+/// propagates back up through nested `resolve_includes` calls.
+fn tag_file(error: Error, file: Option<&Path>) -> Error {
+    match file {
+        Some(file) => error.propagate_file(file),
+        None => error,
+    }
+}
+
+/// The result of resolving a single `<include>`'s `uri`: either a `Model`
+/// (whether it came from an SDF `<model>` element or, with the `urdf`
+/// feature, a converted URDF robot), or a non-model SDF entity (`<world>`,
+/// `<actor>`, `<light>`), which cannot be merged and, unmerged, is dropped.
+enum ResolvedInclude {
+    Model(Box<Model>),
+    NonModel,
+}
+
+/// Resolves and parses the target of an `<include>`'s `uri`, resolving
+/// `model://` references against `search_paths`, directory-style includes
+/// via `model.config`, and — per the SDFormat composition proposal's
+/// format-agnostic include interface — `.urdf` targets via `urdf-rs`.
+/// Returns the resolved path alongside the result so callers can tag errors
+/// surfaced while processing its contents. Any error here is tagged with the
+/// file that was being read when it occurred, falling back to
+/// `current_file` (the include referencing `uri`) when that file itself
+/// could not be found.
+fn load_include(
+    uri: &str,
+    search_paths: &[PathBuf],
+    current_file: Option<&Path>,
+) -> Result<(ResolvedInclude, PathBuf), Error> {
+    let path = resolve_uri_path(uri, search_paths).map_err(|e| tag_file(e, current_file))?;
+
+    if is_urdf(&path) {
+        let model = load_urdf_model(&path)?;
+        return Ok((ResolvedInclude::Model(Box::new(model)), path));
+    }
+
+    let sdf_path = if path.is_dir() {
+        model_config_sdf_path(&path)?
+    } else {
+        path
+    };
+
+    let text = fs::read_to_string(&sdf_path).map_err(|_| {
+        Error::new(ErrorCode::FileNotFound, format!("could not read {uri:?}")).with_file(&sdf_path)
+    })?;
+    let sdf: Sdf = from_str(&text).map_err(|e| parse_failure(e).with_file(&sdf_path))?;
+
+    let resolved = match sdf.sdf_type {
+        SdfType::Model(model) => ResolvedInclude::Model(model),
+        SdfType::World(_) | SdfType::Actor | SdfType::Light => ResolvedInclude::NonModel,
+    };
+    Ok((resolved, sdf_path))
+}
+
+fn is_urdf(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("urdf"))
+}
+
+#[cfg(feature = "urdf")]
+fn load_urdf_model(path: &Path) -> Result<Model, Error> {
+    crate::urdf_bridge::load_model(path)
+}
+
+#[cfg(not(feature = "urdf"))]
+fn load_urdf_model(path: &Path) -> Result<Model, Error> {
+    Err(Error::new(
+        ErrorCode::ParseFailure,
+        "including a .urdf file requires building with the `urdf` feature",
+    )
+    .with_file(path))
+}
+
+fn resolve_uri_path(uri: &str, search_paths: &[PathBuf]) -> Result<PathBuf, Error> {
+    match uri.strip_prefix(MODEL_SCHEME_PREFIX) {
+        Some(relative) => search_paths
+            .iter()
+            .map(|base| base.join(relative))
+            .find(|candidate| candidate.exists())
+            .ok_or_else(|| {
+                Error::new(ErrorCode::FileNotFound, format!("could not resolve {uri:?} in search paths"))
+            }),
+        None => {
+            let direct = PathBuf::from(uri);
+            if direct.exists() {
+                Ok(direct)
+            } else {
+                Err(Error::new(ErrorCode::FileNotFound, format!("could not resolve {uri:?}")))
+            }
+        }
+    }
+}
+
+/// Reads the model-database directory's `model.config` and returns the path
+/// to the canonical SDF file it names.
+///
+/// `model.config` is its own small XML vocabulary (not SDFormat), so this is
+/// a minimal, purpose-built extraction of the `<sdf>` element's text rather
+/// than a full parse of that dialect.
+fn model_config_sdf_path(dir: &Path) -> Result<PathBuf, Error> {
+    let config_path = dir.join("model.config");
+    let config = fs::read_to_string(&config_path).map_err(|_| {
+        Error::new(
+            ErrorCode::FileNotFound,
+            format!("model directory {:?} has no usable model.config", dir),
+        )
+        .with_file(&config_path)
+    })?;
+    let sdf_relative = extract_sdf_element(&config).ok_or_else(|| {
+        Error::new(
+            ErrorCode::ParseFailure,
+            "model.config has no <sdf> element",
+        )
+        .with_file(&config_path)
+    })?;
+    Ok(dir.join(sdf_relative))
+}
+
+fn extract_sdf_element(config: &str) -> Option<String> {
+    let tag_start = config.find("<sdf")?;
+    let tag_close = tag_start + config[tag_start..].find('>')? + 1;
+    let body_end = tag_close + config[tag_close..].find("</sdf>")?;
+    Some(config[tag_close..body_end].trim().to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn extracts_sdf_path_from_model_config() {
+        let config = r#"
+            <?xml version="1.0"?>
+            <model>
+                <name>box</name>
+                <sdf version="1.8">model.sdf</sdf>
+            </model>"#;
+        assert_eq!(extract_sdf_element(config).as_deref(), Some("model.sdf"));
+    }
+
+    /// Returns a fresh directory under the system temp dir for a single test,
+    /// so file-backed `resolve_includes` tests don't collide with each other.
+    fn test_dir(name: &str) -> PathBuf {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = std::env::temp_dir().join(format!("sdformat-resolve-test-{name}-{n}"));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).expect("create test dir");
+        dir
+    }
+
+    fn write_model(dir: &Path, file: &str, name: &str, includes: &str) -> PathBuf {
+        let path = dir.join(file);
+        fs::write(
+            &path,
+            format!(
+                r#"<?xml version="1.0"?>
+                <sdf version="1.8">
+                    <model name="{name}">
+                        {includes}
+                    </model>
+                </sdf>"#
+            ),
+        )
+        .expect("write model file");
+        path
+    }
+
+    #[test]
+    fn same_model_name_from_different_paths_is_not_a_false_cycle() {
+        let dir = test_dir("diamond");
+        let a_dir = dir.join("a");
+        let b_dir = dir.join("b");
+        fs::create_dir_all(&a_dir).unwrap();
+        fs::create_dir_all(&b_dir).unwrap();
+        write_model(&a_dir, "model.sdf", "shared_name", "");
+        write_model(&b_dir, "model.sdf", "shared_name", "");
+
+        let root_path = write_model(
+            &dir,
+            "root.sdf",
+            "root",
+            &format!(
+                r#"<include><uri>{}</uri><name>first</name></include><include><uri>{}</uri><name>second</name></include>"#,
+                a_dir.join("model.sdf").display(),
+                b_dir.join("model.sdf").display(),
+            ),
+        );
+
+        let mut sdf = Sdf::from_path(&root_path).expect("should parse");
+        sdf.resolve_includes(&[]).expect("shared model name across distinct paths must not cycle");
+    }
+
+    #[test]
+    fn including_the_same_file_twice_in_a_chain_is_a_cycle() {
+        // root -> a -> b -> a again, forming a cycle entirely among
+        // file-backed includes (independent of the root document's own,
+        // untracked identity).
+        let dir = test_dir("cycle");
+        let a_path = dir.join("a.sdf");
+        let b_path = dir.join("b.sdf");
+        fs::write(
+            &a_path,
+            format!(
+                r#"<?xml version="1.0"?>
+                <sdf version="1.8">
+                    <model name="a">
+                        <include><uri>{}</uri></include>
+                    </model>
+                </sdf>"#,
+                b_path.display()
+            ),
+        )
+        .unwrap();
+        fs::write(
+            &b_path,
+            format!(
+                r#"<?xml version="1.0"?>
+                <sdf version="1.8">
+                    <model name="b">
+                        <include><uri>{}</uri></include>
+                    </model>
+                </sdf>"#,
+                a_path.display()
+            ),
+        )
+        .unwrap();
+        let root_path = write_model(
+            &dir,
+            "root.sdf",
+            "root",
+            &format!(r#"<include><uri>{}</uri></include>"#, a_path.display()),
+        );
+
+        let mut sdf = Sdf::from_path(&root_path).expect("should parse");
+        let error = sdf
+            .resolve_includes(&[])
+            .expect_err("re-including the same file must be detected as a cycle");
+        assert_eq!(error.code, ErrorCode::IncludeCycle);
+    }
+}
@@ -0,0 +1,165 @@
+//! Maps a [`urdf_rs::Robot`] onto this crate's SDF `Model` types, so a
+//! `.urdf` file can be pulled in through an `<include>` per the SDFormat
+//! composition proposal, which defines `<include>` as a format-agnostic
+//! interface. Only available with the `urdf` feature enabled.
+
+use std::path::Path;
+
+use crate::error::{Error, ErrorCode};
+use crate::{
+    Axis, Collision, Dynamics, Geometry, Inertia, Inertial, Joint, JointType, Limit, Link, Model, Pose, Visual,
+};
+
+/// Reads and parses `path` as URDF, then converts it into an SDF [`Model`].
+pub(crate) fn load_model(path: &Path) -> Result<Model, Error> {
+    let robot = urdf_rs::read_file(path)
+        .map_err(|e| Error::new(ErrorCode::ParseFailure, e.to_string()).with_file(path))?;
+    convert(&robot).map_err(|e| e.with_file(path))
+}
+
+fn convert(robot: &urdf_rs::Robot) -> Result<Model, Error> {
+    let link = robot
+        .links
+        .iter()
+        .map(convert_link)
+        .collect::<Result<Vec<_>, _>>()?;
+    let joint = robot
+        .joints
+        .iter()
+        .map(convert_joint)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Model {
+        name: robot.name.clone(),
+        canonical_link: None,
+        placement_frame: None,
+        r#static: None,
+        self_collide: None,
+        allow_auto_disable: Some(true),
+        include: vec![],
+        model: vec![],
+        enable_wind: Some(false),
+        frame: vec![],
+        pose: None,
+        link,
+        joint,
+        plugin: vec![],
+        gripper: vec![],
+    })
+}
+
+fn convert_link(link: &urdf_rs::Link) -> Result<Link, Error> {
+    Ok(Link {
+        name: link.name.clone(),
+        inertial: Some(Inertial {
+            mass: link.inertial.mass.value,
+            pose: Some(convert_pose(&link.inertial.origin)),
+            inertia: Inertia {
+                ixx: link.inertial.inertia.ixx,
+                ixy: link.inertial.inertia.ixy,
+                ixz: link.inertial.inertia.ixz,
+                iyy: link.inertial.inertia.iyy,
+                iyz: link.inertial.inertia.iyz,
+                izz: link.inertial.inertia.izz,
+            },
+        }),
+        collision: link
+            .collision
+            .iter()
+            .map(convert_collision)
+            .collect::<Result<_, _>>()?,
+        visual: link
+            .visual
+            .iter()
+            .map(convert_visual)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn convert_collision(collision: &urdf_rs::Collision) -> Result<Collision, Error> {
+    Ok(Collision {
+        name: collision.name.clone().unwrap_or_default(),
+        pose: Some(convert_pose(&collision.origin)),
+        geometry: convert_geometry(&collision.geometry)?,
+    })
+}
+
+fn convert_visual(visual: &urdf_rs::Visual) -> Result<Visual, Error> {
+    Ok(Visual {
+        name: visual.name.clone().unwrap_or_default(),
+        pose: Some(convert_pose(&visual.origin)),
+        geometry: convert_geometry(&visual.geometry)?,
+    })
+}
+
+fn convert_geometry(geometry: &urdf_rs::Geometry) -> Result<Geometry, Error> {
+    match geometry {
+        urdf_rs::Geometry::Box { size } => Ok(Geometry::Box { size: (**size) }),
+        urdf_rs::Geometry::Cylinder { radius, length } => Ok(Geometry::Cylinder {
+            radius: *radius,
+            length: *length,
+        }),
+        urdf_rs::Geometry::Sphere { radius } => Ok(Geometry::Sphere { radius: *radius }),
+        urdf_rs::Geometry::Capsule { radius, length } => Ok(Geometry::Capsule {
+            radius: *radius,
+            length: *length,
+        }),
+        urdf_rs::Geometry::Mesh { filename, scale } => Ok(Geometry::Mesh {
+            uri: filename.clone(),
+            scale: scale.map(|s| *s).unwrap_or([1.0, 1.0, 1.0]),
+        }),
+    }
+}
+
+fn convert_pose(pose: &urdf_rs::Pose) -> Pose {
+    Pose::new(
+        pose.xyz[0],
+        pose.xyz[1],
+        pose.xyz[2],
+        pose.rpy[0],
+        pose.rpy[1],
+        pose.rpy[2],
+    )
+}
+
+fn convert_joint(joint: &urdf_rs::Joint) -> Result<Joint, Error> {
+    Ok(Joint {
+        name: joint.name.clone(),
+        r#type: convert_joint_type(&joint.joint_type)?,
+        parent: joint.parent.link.clone(),
+        child: joint.child.link.clone(),
+        pose: Some(convert_pose(&joint.origin)),
+        axis: Some(Axis {
+            xyz: *joint.axis.xyz,
+            limit: Some(Limit {
+                lower: joint.limit.lower,
+                upper: joint.limit.upper,
+                effort: Some(joint.limit.effort),
+                velocity: Some(joint.limit.velocity),
+            }),
+            dynamics: joint.dynamics.as_ref().map(convert_dynamics),
+        }),
+    })
+}
+
+fn convert_dynamics(dynamics: &urdf_rs::Dynamics) -> Dynamics {
+    Dynamics {
+        damping: Some(dynamics.damping),
+        friction: Some(dynamics.friction),
+        spring_stiffness: None,
+        spring_reference: None,
+    }
+}
+
+fn convert_joint_type(joint_type: &urdf_rs::JointType) -> Result<JointType, Error> {
+    match joint_type {
+        urdf_rs::JointType::Revolute => Ok(JointType::Revolute),
+        urdf_rs::JointType::Continuous => Ok(JointType::Continuous),
+        urdf_rs::JointType::Prismatic => Ok(JointType::Prismatic),
+        urdf_rs::JointType::Fixed => Ok(JointType::Fixed),
+        unsupported => Err(Error::new(
+            ErrorCode::ParseFailure,
+            format!("urdf joint type {unsupported:?} has no SDF equivalent"),
+        )),
+    }
+}
@@ -0,0 +1,35 @@
+//! Parses and formats `[f64; 3]` fields from/to a whitespace-separated
+//! string, following the `x y z` convention used throughout SDFormat.
+//! Used via `#[serde(with = "vec3")]` on fields such as `Geometry::Box::size`
+//! and `Axis::xyz`.
+
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Returns `[1.0, 1.0, 1.0]`, the default for `Geometry::Mesh::scale`.
+pub fn ones() -> [f64; 3] {
+    [1.0, 1.0, 1.0]
+}
+
+pub fn serialize<S: Serializer>(value: &[f64; 3], serializer: S) -> Result<S::Ok, S::Error> {
+    format!("{} {} {}", value[0], value[1], value[2]).serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[f64; 3], D::Error> {
+    let text = String::deserialize(deserializer)?;
+    parse(&text).map_err(DeError::custom)
+}
+
+fn parse(text: &str) -> Result<[f64; 3], String> {
+    let values = text
+        .split_whitespace()
+        .map(|v| {
+            v.parse::<f64>()
+                .map_err(|e| format!("invalid vec3 component {:?}: {}", v, e))
+        })
+        .collect::<Result<Vec<f64>, String>>()?;
+
+    values.try_into().map_err(|values: Vec<f64>| {
+        format!("vec3 must have exactly 3 components, got {}", values.len())
+    })
+}